@@ -87,6 +87,15 @@ pub enum Bundler {
     Zisi,
 }
 
+/// The minimum TLS version a redirect rule requires to be served.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum TlsVersion {
+    #[serde(alias = "tls12", alias = "TLSv1.2", alias = "1.2")]
+    Tls12,
+    #[serde(alias = "tls13", alias = "TLSv1.3", alias = "1.3")]
+    Tls13,
+}
+
 /// Redirect holds information about a url redirect.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Redirect {
@@ -98,13 +107,26 @@ pub struct Redirect {
     pub status: u32,
     #[serde(default)]
     pub force: bool,
+    pub signed: Option<String>,
+    #[serde(alias = "edge-handler")]
+    pub edge_handler: Option<String>,
+    /// The minimum TLS version required to serve this rule. A request
+    /// negotiated below this version, or without TLS, resolves to
+    /// [`Resolution::Forbidden`].
+    #[serde(
+        alias = "minTLSVersion",
+        alias = "min-tls-version",
+        alias = "tls_version"
+    )]
+    pub min_tls_version: Option<TlsVersion>,
+    /// Roles that gate this rule, distinct from `conditions.Role`. A
+    /// request must carry one of them for the rule to serve.
+    #[serde(default, alias = "role", skip_serializing_if = "HashSet::is_empty")]
+    pub roles: HashSet<String>,
     pub headers: Option<HashMap<String, String>>,
     #[serde(alias = "params", alias = "parameters")]
     pub query: Option<HashMap<String, String>>,
     pub conditions: Option<HashMap<String, HashSet<String>>>,
-    pub signed: Option<String>,
-    #[serde(alias = "edge-handler")]
-    pub edge_handler: Option<String>,
 }
 
 /// Header holds information to add response headers for a give url.
@@ -163,6 +185,28 @@ pub fn from_str(io: &str) -> Result<Config, Error> {
     toml::from_str(io)
 }
 
+/// Serializes a [`Config`] back to canonical TOML.
+///
+/// This is the inverse of [`from_str`]: the output round-trips through the
+/// parser, including [`HeaderValues`], which is emitted as a bare string
+/// when it holds a single value and as an array when it holds several. A
+/// single value that contains a comma is read back as several values.
+///
+/// # Example
+///
+/// ```
+/// let config = netlify_toml::ConfigBuilder::new()
+///     .redirect(netlify_toml::RedirectBuilder::new().from("/a").to("/b").build())
+///     .build();
+///
+/// let toml = netlify_toml::to_string(&config).unwrap();
+/// assert!(toml.contains("from = \"/a\""));
+/// ```
+#[inline]
+pub fn to_string(config: &Config) -> Result<String, toml::ser::Error> {
+    toml::to_string(config)
+}
+
 impl Config {
     /// Returns a HashMap that aggregates all environment variables for
     /// a context within a git branch.
@@ -281,6 +325,745 @@ impl Serialize for HeaderValues {
     }
 }
 
+/// An incoming request to resolve against a config's redirect rules.
+///
+/// The caller is responsible for extracting these values from the HTTP
+/// request: `languages` from the `Accept-Language` header, `country` from
+/// the geo/CDN country code, and `roles` from a verified JWT or signed
+/// cookie.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Request {
+    /// The request path, for example `/api/posts/42`.
+    pub path: String,
+    /// The parsed query string parameters.
+    pub query: HashMap<String, String>,
+    /// The languages advertised by the client, for example `en`, `es`.
+    pub languages: HashSet<String>,
+    /// The client's country code, for example `US`.
+    pub country: Option<String>,
+    /// The roles granted to the client by a verified token.
+    pub roles: HashSet<String>,
+    /// The TLS version the connection was negotiated with, if any.
+    pub tls_version: Option<TlsVersion>,
+}
+
+/// The outcome of resolving a [`Request`] against the redirect rules.
+///
+/// The variant reflects the rule's `status`: a 200 rule is served in place
+/// (a [`Rewrite`](Resolution::Rewrite) for an internal target, or a
+/// [`Proxy`](Resolution::Proxy) for an absolute URL), while a 3xx rule is a
+/// browser [`Redirect`](Resolution::Redirect).
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resolution<'a> {
+    /// A browser redirect with the rule's 3xx status code.
+    Redirect {
+        status: u32,
+        url: String,
+        rule: &'a Redirect,
+    },
+    /// A 200 rule whose target is an internal path served transparently.
+    Rewrite { url: String, rule: &'a Redirect },
+    /// A 200 rule whose target is an absolute URL proxied upstream.
+    Proxy { url: String, rule: &'a Redirect },
+    /// A rule that matched but whose TLS-version or role requirement the
+    /// request did not meet, gating the route with a 403.
+    Forbidden { rule: &'a Redirect },
+}
+
+impl Config {
+    /// Resolves an incoming request against the redirect rules, returning
+    /// the first matching rule and its computed target.
+    ///
+    /// Rules are walked top-to-bottom, mirroring how Netlify applies them.
+    /// `file_exists` is consulted for non-forced 200 rules: a proxy or
+    /// rewrite that would otherwise shadow an existing static file is
+    /// skipped unless the rule sets `force = true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let io = r#"
+    /// [[redirects]]
+    ///   from = "/api/*"
+    ///   to = "https://api.example.com/:splat"
+    ///   status = 200
+    /// "#;
+    ///
+    /// let config = netlify_toml::from_str(io).unwrap();
+    /// let req = netlify_toml::Request {
+    ///     path: "/api/posts".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let resolution = config.resolve_redirect(&req, |_| false);
+    /// assert!(resolution.is_some());
+    /// ```
+    pub fn resolve_redirect(
+        &self,
+        req: &Request,
+        file_exists: impl Fn(&str) -> bool,
+    ) -> Option<Resolution<'_>> {
+        let redirects = self.redirects.as_ref()?;
+        for rule in redirects {
+            if let Some(resolution) = rule.resolve(req, &file_exists) {
+                return Some(resolution);
+            }
+        }
+        None
+    }
+}
+
+impl Redirect {
+    /// Resolves a single rule against the request, returning its outcome
+    /// when the rule matches and `None` otherwise.
+    pub fn resolve(
+        &self,
+        req: &Request,
+        file_exists: impl Fn(&str) -> bool,
+    ) -> Option<Resolution<'_>> {
+        let mut bindings = match_from(&self.from, &req.path)?;
+
+        if !self.conditions_match(req) {
+            return None;
+        }
+
+        if let Some(ref query) = self.query {
+            for (param, placeholder) in query {
+                let value = req.query.get(param)?;
+                if let Some(name) = placeholder.strip_prefix(':') {
+                    bindings.insert(name.to_string(), value.to_owned());
+                }
+            }
+        }
+
+        // A non-forced in-place (200) rule yields to an existing static
+        // file before any access gate runs, so a shadowed path serves the
+        // file rather than returning Forbidden to an unauthorized request.
+        if self.status == 200 && !self.force && file_exists(&req.path) {
+            return None;
+        }
+
+        // The rule applies to this request; an unmet TLS or role
+        // requirement gates it with a 403 rather than falling through.
+        if !self.access_granted(req) {
+            return Some(Resolution::Forbidden { rule: self });
+        }
+
+        let url = substitute(self.to.as_ref()?, &bindings);
+
+        if self.status == 200 {
+            if is_absolute_url(&url) {
+                Some(Resolution::Proxy { url, rule: self })
+            } else {
+                Some(Resolution::Rewrite { url, rule: self })
+            }
+        } else {
+            Some(Resolution::Redirect {
+                status: self.status,
+                url,
+                rule: self,
+            })
+        }
+    }
+
+    /// Returns true when every condition is satisfied by set membership
+    /// against the request's corresponding values.
+    fn conditions_match(&self, req: &Request) -> bool {
+        let conditions = match self.conditions {
+            Some(ref c) => c,
+            None => return true,
+        };
+
+        for (key, values) in conditions {
+            let satisfied = match key.as_str() {
+                "Language" => !req.languages.is_disjoint(values),
+                "Country" => req.country.as_ref().is_some_and(|c| values.contains(c)),
+                "Role" => !req.roles.is_disjoint(values),
+                // Other keys, including `Cookie`, are not checked here; keys
+                // outside the recognized set are flagged by the validation pass.
+                _ => true,
+            };
+            if !satisfied {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns true when the request satisfies the rule's access-control
+    /// constraints: a required role is held and the negotiated TLS version
+    /// is at least the minimum.
+    fn access_granted(&self, req: &Request) -> bool {
+        if !self.roles.is_empty() && req.roles.is_disjoint(&self.roles) {
+            return false;
+        }
+
+        if let Some(required) = self.min_tls_version {
+            match req.tls_version {
+                Some(version) if version >= required => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+/// Matches an incoming `path` against a `from` pattern, binding `:splat`
+/// (a trailing `*`) and `:name` path segments. Returns the captured
+/// bindings when the whole path is consumed by the pattern.
+fn match_from(from: &str, path: &str) -> Option<HashMap<String, String>> {
+    let pattern: Vec<&str> = from.split('/').filter(|s| !s.is_empty()).collect();
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let mut bindings = HashMap::new();
+    for (i, part) in pattern.iter().enumerate() {
+        if *part == "*" {
+            let splat = segments.get(i..).map(|s| s.join("/")).unwrap_or_default();
+            bindings.insert("splat".to_string(), splat);
+            return Some(bindings);
+        }
+
+        let segment = segments.get(i)?;
+        if let Some(name) = part.strip_prefix(':') {
+            bindings.insert(name.to_string(), (*segment).to_string());
+        } else if part != segment {
+            return None;
+        }
+    }
+
+    // Without a splat the pattern must consume the whole path.
+    if segments.len() != pattern.len() {
+        return None;
+    }
+    Some(bindings)
+}
+
+/// Substitutes `:splat` and `:name` placeholders in `to` with their bound
+/// values. Longer names are replaced first so they are not clobbered by a
+/// shorter name that is a prefix of them.
+fn substitute(to: &str, bindings: &HashMap<String, String>) -> String {
+    let mut keys: Vec<&String> = bindings.keys().collect();
+    keys.sort_by_key(|k| std::cmp::Reverse(k.len()));
+
+    let mut out = to.to_string();
+    for key in keys {
+        out = out.replace(&format!(":{}", key), &bindings[key]);
+    }
+    out
+}
+
+/// Layers one configuration value on top of another.
+///
+/// Merging is how several configuration sources are resolved into one: a
+/// build-plugin pipeline can start from the repository's `netlify.toml`,
+/// layer in a plugin-generated [`Config`], then apply a context override,
+/// and end up with a single fully-resolved value.
+///
+/// The later source wins on conflict. `Option` fields take the other's
+/// value when it is `Some` (merging the two when both are set), map fields
+/// merge key-by-key, and the redirect
+/// and header vectors merge by their key (`from` and `for`), replacing
+/// matching entries in place to preserve ordering and appending the rest.
+pub trait Merge {
+    /// Layers `other` on top of `self`, consuming `other`.
+    fn merge(&mut self, other: Self);
+}
+
+/// Leaf values that are simply overwritten by the later source.
+macro_rules! merge_replace {
+    ($($t:ty),* $(,)?) => {$(
+        impl Merge for $t {
+            fn merge(&mut self, other: Self) {
+                *self = other;
+            }
+        }
+    )*};
+}
+merge_replace!(String, Bundler);
+
+impl<T: Merge> Merge for Option<T> {
+    fn merge(&mut self, other: Option<T>) {
+        if let Some(other) = other {
+            match self {
+                Some(current) => current.merge(other),
+                None => *self = Some(other),
+            }
+        }
+    }
+}
+
+impl<K, V> Merge for HashMap<K, V>
+where
+    K: std::cmp::Eq + std::hash::Hash,
+{
+    fn merge(&mut self, other: Self) {
+        self.extend(other);
+    }
+}
+
+impl Merge for Vec<String> {
+    fn merge(&mut self, other: Self) {
+        for item in other {
+            if !self.contains(&item) {
+                self.push(item);
+            }
+        }
+    }
+}
+
+impl Merge for Vec<Redirect> {
+    fn merge(&mut self, other: Self) {
+        for rule in other {
+            match self.iter_mut().find(|r| r.from == rule.from) {
+                Some(existing) => *existing = rule,
+                None => self.push(rule),
+            }
+        }
+    }
+}
+
+impl Merge for Vec<Header> {
+    fn merge(&mut self, other: Self) {
+        for header in other {
+            match self.iter_mut().find(|h| h.path == header.path) {
+                Some(existing) => *existing = header,
+                None => self.push(header),
+            }
+        }
+    }
+}
+
+impl Merge for Vec<EdgeHandler> {
+    fn merge(&mut self, other: Self) {
+        for handler in other {
+            match self.iter_mut().find(|h| h.path == handler.path) {
+                Some(existing) => *existing = handler,
+                None => self.push(handler),
+            }
+        }
+    }
+}
+
+impl Merge for Build {
+    fn merge(&mut self, other: Build) {
+        self.base.merge(other.base);
+        self.command.merge(other.command);
+        self.functions.merge(other.functions);
+        self.environment.merge(other.environment);
+        self.edge_handlers.merge(other.edge_handlers);
+        self.publish.merge(other.publish);
+    }
+}
+
+impl Merge for Functions {
+    fn merge(&mut self, other: Functions) {
+        self.directory.merge(other.directory);
+        self.external_node_modules.merge(other.external_node_modules);
+        self.ignored_node_modules.merge(other.ignored_node_modules);
+        self.included_files.merge(other.included_files);
+        self.node_bundler.merge(other.node_bundler);
+    }
+}
+
+impl Merge for Template {
+    fn merge(&mut self, other: Template) {
+        self.hooks.merge(other.hooks);
+        self.environment.merge(other.environment);
+    }
+}
+
+impl Merge for Config {
+    fn merge(&mut self, other: Config) {
+        self.build.merge(other.build);
+        self.context.merge(other.context);
+        self.edge_handlers.merge(other.edge_handlers);
+        self.functions.merge(other.functions);
+        self.headers.merge(other.headers);
+        self.redirects.merge(other.redirects);
+        self.template.merge(other.template);
+    }
+}
+
+impl Config {
+    /// Folds a sequence of configuration sources into one, layering each
+    /// on top of the previous with [`Merge`].
+    ///
+    /// The first source is the base and later sources override it, so a
+    /// pipeline passes the repository config first and its overrides last.
+    pub fn merge_all(sources: impl IntoIterator<Item = Config>) -> Config {
+        let mut result = Config::default();
+        for source in sources {
+            result.merge(source);
+        }
+        result
+    }
+}
+
+impl HeaderValues {
+    /// Builds a `HeaderValues` from any iterator of string-like values.
+    pub fn new(values: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        HeaderValues {
+            values: values.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A fluent builder for [`Redirect`] rules.
+///
+/// Chain the setters and call [`build`](RedirectBuilder::build); unset
+/// fields fall back to [`Redirect`]'s defaults (`status = 301`,
+/// `force = false`).
+#[derive(Clone, Debug, Default)]
+pub struct RedirectBuilder {
+    redirect: Redirect,
+}
+
+impl RedirectBuilder {
+    pub fn new() -> Self {
+        RedirectBuilder::default()
+    }
+
+    pub fn from(mut self, from: impl Into<String>) -> Self {
+        self.redirect.from = from.into();
+        self
+    }
+
+    pub fn to(mut self, to: impl Into<String>) -> Self {
+        self.redirect.to = Some(to.into());
+        self
+    }
+
+    pub fn status(mut self, status: u32) -> Self {
+        self.redirect.status = status;
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.redirect.force = force;
+        self
+    }
+
+    pub fn signed(mut self, signed: impl Into<String>) -> Self {
+        self.redirect.signed = Some(signed.into());
+        self
+    }
+
+    /// Adds a condition, for example `.condition("Country", ["US"])`.
+    pub fn condition(
+        mut self,
+        key: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        let values = values.into_iter().map(Into::into).collect();
+        self.redirect
+            .conditions
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), values);
+        self
+    }
+
+    /// Binds an incoming query parameter to a placeholder, for example
+    /// `.query("id", ":id")`.
+    pub fn query(mut self, param: impl Into<String>, placeholder: impl Into<String>) -> Self {
+        self.redirect
+            .query
+            .get_or_insert_with(HashMap::new)
+            .insert(param.into(), placeholder.into());
+        self
+    }
+
+    pub fn build(self) -> Redirect {
+        self.redirect
+    }
+}
+
+/// A fluent builder for [`Header`] rules.
+#[derive(Clone, Debug, Default)]
+pub struct HeaderBuilder {
+    header: Header,
+}
+
+impl HeaderBuilder {
+    pub fn new() -> Self {
+        HeaderBuilder::default()
+    }
+
+    /// Sets the path the header applies to (the `for` key).
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.header.path = path.into();
+        self
+    }
+
+    /// Adds a header and its value(s).
+    pub fn value(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.header.headers.insert(name.into(), HeaderValues::new(values));
+        self
+    }
+
+    pub fn build(self) -> Header {
+        self.header
+    }
+}
+
+/// A fluent builder for a whole [`Config`].
+#[derive(Clone, Debug, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        ConfigBuilder::default()
+    }
+
+    pub fn build_settings(mut self, build: Build) -> Self {
+        self.config.build = Some(build);
+        self
+    }
+
+    pub fn functions(mut self, functions: Functions) -> Self {
+        self.config.functions = Some(functions);
+        self
+    }
+
+    pub fn redirect(mut self, redirect: Redirect) -> Self {
+        self.config
+            .redirects
+            .get_or_insert_with(Vec::new)
+            .push(redirect);
+        self
+    }
+
+    pub fn header(mut self, header: Header) -> Self {
+        self.config.headers.get_or_insert_with(Vec::new).push(header);
+        self
+    }
+
+    pub fn build(self) -> Config {
+        self.config
+    }
+}
+
+/// The kind of a [`ConfigError`], suitable for programmatic matching.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConfigErrorKind {
+    /// A redirect `status` outside the 2xx/3xx families, 404 or 410.
+    InvalidStatus,
+    /// A proxy rule (`status` 200) whose `to` is not an absolute URL.
+    ProxyTargetNotAbsolute,
+    /// A `to` placeholder that `from` and `query` do not bind.
+    PlaceholderMismatch,
+    /// A `conditions` key outside Language, Country, Role and Cookie.
+    UnknownCondition,
+    /// A header name containing characters illegal in an HTTP field name.
+    InvalidHeaderName,
+    /// `signed` set without a secret-looking value.
+    MissingSignedSecret,
+}
+
+/// A single semantic problem found by [`Config::validate`].
+///
+/// Unlike a parse error, a `ConfigError` describes a rule that is
+/// well-formed TOML but invalid in meaning. The `kind` is machine-readable
+/// and `message` is a human-facing description, so a CLI can report every
+/// problem at once.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigError {
+    pub kind: ConfigErrorKind,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(kind: ConfigErrorKind, message: impl Into<String>) -> Self {
+        ConfigError {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Validates the configuration's semantics, collecting every problem.
+    ///
+    /// [`from_str`] only rejects malformed TOML; rules that parse cleanly
+    /// can still be meaningless. `validate` walks the redirects and headers
+    /// and returns all [`ConfigError`]s at once rather than failing on the
+    /// first, so a caller can surface the complete list.
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        if let Some(ref redirects) = self.redirects {
+            for redirect in redirects {
+                redirect.validate_into(&mut errors);
+            }
+        }
+
+        if let Some(ref headers) = self.headers {
+            for header in headers {
+                header.validate_into(&mut errors);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl Redirect {
+    fn validate_into(&self, errors: &mut Vec<ConfigError>) {
+        let status = self.status;
+        let allowed = matches!(status, 200..=399 | 404 | 410);
+        if !allowed {
+            errors.push(ConfigError::new(
+                ConfigErrorKind::InvalidStatus,
+                format!("redirect from `{}` has unsupported status {}", self.from, status),
+            ));
+        }
+
+        if let Some(ref to) = self.to {
+            // A `status = 200` rule with an internal `to` is a legitimate
+            // rewrite (see `Redirect::resolve`); only a target that reads as
+            // a proxy — carrying a scheme or protocol-relative prefix — is
+            // required to be a well-formed absolute URL.
+            if status == 200 && looks_like_proxy_target(to) && !is_absolute_url(to) {
+                errors.push(ConfigError::new(
+                    ConfigErrorKind::ProxyTargetNotAbsolute,
+                    format!("proxy rule from `{}` must point `to` an absolute URL", self.from),
+                ));
+            }
+
+            let mut available = from_placeholders(&self.from);
+            if let Some(ref query) = self.query {
+                for placeholder in query.values() {
+                    if let Some(name) = placeholder.strip_prefix(':') {
+                        available.insert(name.to_string());
+                    }
+                }
+            }
+            for name in to_placeholders(to) {
+                if !available.contains(&name) {
+                    errors.push(ConfigError::new(
+                        ConfigErrorKind::PlaceholderMismatch,
+                        format!(
+                            "redirect from `{}` references `:{}` in `to` but `from` does not bind it",
+                            self.from, name
+                        ),
+                    ));
+                }
+            }
+        }
+
+        if let Some(ref conditions) = self.conditions {
+            for key in conditions.keys() {
+                if !matches!(key.as_str(), "Language" | "Country" | "Role" | "Cookie") {
+                    errors.push(ConfigError::new(
+                        ConfigErrorKind::UnknownCondition,
+                        format!("redirect from `{}` has unknown condition `{}`", self.from, key),
+                    ));
+                }
+            }
+        }
+
+        if let Some(ref signed) = self.signed {
+            if signed.trim().is_empty() {
+                errors.push(ConfigError::new(
+                    ConfigErrorKind::MissingSignedSecret,
+                    format!("redirect from `{}` is `signed` without a secret value", self.from),
+                ));
+            }
+        }
+    }
+}
+
+impl Header {
+    fn validate_into(&self, errors: &mut Vec<ConfigError>) {
+        for name in self.headers.keys() {
+            if !is_valid_header_name(name) {
+                errors.push(ConfigError::new(
+                    ConfigErrorKind::InvalidHeaderName,
+                    format!("header for `{}` has illegal field name `{}`", self.path, name),
+                ));
+            }
+        }
+    }
+}
+
+/// Collects the placeholder names `from` binds: `splat` for a `*` segment
+/// and the name of each `:name` segment.
+fn from_placeholders(from: &str) -> HashSet<String> {
+    let mut set = HashSet::new();
+    for segment in from.split('/') {
+        if segment == "*" {
+            set.insert("splat".to_string());
+        } else if let Some(name) = segment.strip_prefix(':') {
+            set.insert(name.to_string());
+        }
+    }
+    set
+}
+
+/// Collects the placeholder names referenced in a `to` target, i.e. each
+/// `:name` run of identifier characters.
+fn to_placeholders(to: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let bytes = to.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        // A placeholder name starts with an ASCII letter or `_`; a `:`
+        // followed by a digit is a port (e.g. `:8080`), not a binding.
+        if bytes[i] == b':' && i + 1 < bytes.len() && (bytes[i + 1].is_ascii_alphabetic() || bytes[i + 1] == b'_') {
+            let start = i + 1;
+            let mut end = start;
+            while end < bytes.len()
+                && (bytes[end].is_ascii_alphanumeric() || bytes[end] == b'_')
+            {
+                end += 1;
+            }
+            names.push(to[start..end].to_string());
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    names
+}
+
+/// Returns true when every character is a valid HTTP field-name token char
+/// (RFC 7230 `tchar`).
+fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.bytes().all(|b| {
+            b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+        })
+}
+
+fn is_absolute_url(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// Returns true when `to` reads as an off-site proxy target rather than an
+/// internal rewrite path: it carries a URL scheme (`foo://`) or a
+/// protocol-relative prefix (`//host`).
+fn looks_like_proxy_target(to: &str) -> bool {
+    to.starts_with("//") || to.contains("://")
+}
+
 fn default_status() -> u32 {
     301
 }
@@ -297,6 +1080,8 @@ impl Default for Redirect {
             query: None,
             headers: None,
             edge_handler: None,
+            min_tls_version: None,
+            roles: HashSet::new(),
         }
     }
 }
@@ -324,6 +1109,8 @@ mod tests {
             conditions: None,
             signed: None,
             edge_handler: None,
+            min_tls_version: None,
+            roles: HashSet::new(),
         };
 
         let r2 = Redirect {
@@ -336,6 +1123,8 @@ mod tests {
             conditions: None,
             signed: None,
             edge_handler: None,
+            min_tls_version: None,
+            roles: HashSet::new(),
         };
         assert_eq!(r, r2)
     }
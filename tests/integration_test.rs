@@ -216,6 +216,302 @@ fn test_unique_redirect_conditions() {
     assert!(lang.contains("es"));
 }
 
+#[test]
+fn resolve_redirect_binds_splat_and_proxies() {
+    let io = r#"
+[[redirects]]
+  from = "/api/*"
+  to = "https://api.example.com/:splat"
+  status = 200
+    "#;
+
+    let config = netlify_toml::from_str(io).unwrap();
+    let req = netlify_toml::Request {
+        path: "/api/posts/42".to_string(),
+        ..Default::default()
+    };
+
+    match config.resolve_redirect(&req, |_| false) {
+        Some(netlify_toml::Resolution::Proxy { url, .. }) => {
+            assert_eq!("https://api.example.com/posts/42", url);
+        }
+        other => panic!("expected a proxy, got {:?}", other),
+    }
+}
+
+#[test]
+fn resolve_redirect_honors_conditions_and_status() {
+    let io = r#"
+[[redirects]]
+  from = "/dashboard"
+  to = "/login"
+  status = 302
+  conditions = {Role = ["admin"], Country = ["US"]}
+    "#;
+
+    let config = netlify_toml::from_str(io).unwrap();
+
+    let mut matching = netlify_toml::Request {
+        path: "/dashboard".to_string(),
+        country: Some("US".to_string()),
+        ..Default::default()
+    };
+    matching.roles.insert("admin".to_string());
+
+    match config.resolve_redirect(&matching, |_| false) {
+        Some(netlify_toml::Resolution::Redirect { status, url, .. }) => {
+            assert_eq!(302, status);
+            assert_eq!("/login", url);
+        }
+        other => panic!("expected a redirect, got {:?}", other),
+    }
+
+    // A request missing the required role does not match.
+    let anonymous = netlify_toml::Request {
+        path: "/dashboard".to_string(),
+        country: Some("US".to_string()),
+        ..Default::default()
+    };
+    assert!(config.resolve_redirect(&anonymous, |_| false).is_none());
+}
+
+#[test]
+fn resolve_redirect_skips_non_forced_proxy_when_file_exists() {
+    let io = r#"
+[[redirects]]
+  from = "/app/*"
+  to = "https://app.example.com/:splat"
+  status = 200
+    "#;
+
+    let config = netlify_toml::from_str(io).unwrap();
+    let req = netlify_toml::Request {
+        path: "/app/index.html".to_string(),
+        ..Default::default()
+    };
+
+    assert!(config.resolve_redirect(&req, |_| true).is_none());
+    assert!(config.resolve_redirect(&req, |_| false).is_some());
+}
+
+#[test]
+fn merge_layers_sources_deterministically() {
+    use netlify_toml::Merge;
+
+    let base = netlify_toml::from_str(
+        r#"
+[build]
+  command = "make site"
+  environment = {NODE_ENV = "production", KEEP = "1"}
+
+[[redirects]]
+  from = "/api/*"
+  to = "https://prod.api.com/:splat"
+
+[[redirects]]
+  from = "/old"
+  to = "/new"
+    "#,
+    )
+    .unwrap();
+
+    let overlay = netlify_toml::from_str(
+        r#"
+[build]
+  environment = {NODE_ENV = "preview"}
+
+[[redirects]]
+  from = "/api/*"
+  to = "https://staging.api.com/:splat"
+
+[[redirects]]
+  from = "/fresh"
+  to = "/brand-new"
+    "#,
+    )
+    .unwrap();
+
+    let mut config = base;
+    config.merge(overlay);
+
+    let build = config.build.unwrap();
+    // Unset field is retained, environment merges key-by-key.
+    assert_eq!("make site", build.command.unwrap());
+    let env = build.environment.unwrap();
+    assert_eq!("preview", env["NODE_ENV"]);
+    assert_eq!("1", env["KEEP"]);
+
+    // The `/api/*` rule is replaced in place, the new rule appended.
+    let redirects = config.redirects.unwrap();
+    assert_eq!(3, redirects.len());
+    assert_eq!("/api/*", redirects[0].from);
+    assert_eq!(
+        "https://staging.api.com/:splat",
+        redirects[0].to.as_deref().unwrap()
+    );
+    assert_eq!("/fresh", redirects[2].from);
+}
+
+#[test]
+fn merge_all_folds_in_order() {
+    let sources = vec![
+        netlify_toml::from_str("[build]\n  command = \"a\"").unwrap(),
+        netlify_toml::from_str("[build]\n  command = \"b\"").unwrap(),
+    ];
+
+    let config = netlify_toml::Config::merge_all(sources);
+    assert_eq!("b", config.build.unwrap().command.unwrap());
+}
+
+#[test]
+fn builders_round_trip_through_serializer() {
+    use netlify_toml::{ConfigBuilder, HeaderBuilder, RedirectBuilder};
+
+    let config = ConfigBuilder::new()
+        .redirect(
+            RedirectBuilder::new()
+                .from("/old")
+                .to("/new")
+                .status(302)
+                .force(true)
+                .condition("Country", ["US", "CA"])
+                .query("id", ":id")
+                .signed("TOKEN")
+                .build(),
+        )
+        .header(
+            HeaderBuilder::new()
+                .path("/assets/*")
+                .value("X-Single", ["one"])
+                .value("X-Multi", ["a", "b"])
+                .build(),
+        )
+        .build();
+
+    let toml = netlify_toml::to_string(&config).expect("serialize");
+    let reparsed = netlify_toml::from_str(&toml).expect("reparse");
+    assert_eq!(config, reparsed);
+
+    let redirect = &reparsed.redirects.unwrap()[0];
+    assert_eq!("/old", redirect.from);
+    assert_eq!(302, redirect.status);
+    assert!(redirect.force);
+
+    let headers = reparsed.headers.unwrap();
+    let values = &headers[0].headers;
+    assert_eq!(1, values["X-Single"].values.len());
+    assert_eq!(2, values["X-Multi"].values.len());
+}
+
+#[test]
+fn validate_accepts_well_formed_rules() {
+    let config = netlify_toml::from_str(
+        r#"
+[[redirects]]
+  from = "/api/*"
+  to = "https://api.example.com/:splat"
+  status = 200
+
+[[redirects]]
+  from = "/blog/:slug"
+  to = "/posts/:slug"
+  conditions = {Country = ["US"]}
+
+[[headers]]
+  for = "/*"
+  [headers.values]
+    X-Frame-Options = "DENY"
+    "#,
+    )
+    .unwrap();
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_collects_all_problems() {
+    let config = netlify_toml::from_str(
+        r#"
+[[redirects]]
+  from = "/one"
+  to = "/two"
+  status = 500
+
+[[redirects]]
+  from = "/proxy"
+  to = "//api.example.com/internal"
+  status = 200
+
+[[redirects]]
+  from = "/blog/:slug"
+  to = "/posts/:missing"
+  conditions = {Planet = ["earth"]}
+  signed = ""
+
+[[headers]]
+  for = "/*"
+  [headers.values]
+    "Bad Header" = "x"
+    "#,
+    )
+    .unwrap();
+
+    let errors = config.validate().expect_err("expected validation errors");
+    let kinds: Vec<netlify_toml::ConfigErrorKind> = errors.iter().map(|e| e.kind).collect();
+
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::InvalidStatus));
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::ProxyTargetNotAbsolute));
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::PlaceholderMismatch));
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::UnknownCondition));
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::MissingSignedSecret));
+    assert!(kinds.contains(&netlify_toml::ConfigErrorKind::InvalidHeaderName));
+}
+
+#[test]
+fn resolve_redirect_gates_on_tls_and_roles() {
+    let io = r#"
+[[redirects]]
+  from = "/secure/*"
+  to = "/app/:splat"
+  status = 200
+  min_tls_version = "Tls13"
+  roles = ["member"]
+    "#;
+
+    let config = netlify_toml::from_str(io).unwrap();
+
+    // A member on TLS 1.3 is served.
+    let mut allowed = netlify_toml::Request {
+        path: "/secure/dashboard".to_string(),
+        tls_version: Some(netlify_toml::TlsVersion::Tls13),
+        ..Default::default()
+    };
+    allowed.roles.insert("member".to_string());
+    assert!(matches!(
+        config.resolve_redirect(&allowed, |_| false),
+        Some(netlify_toml::Resolution::Rewrite { .. })
+    ));
+
+    // The same member on TLS 1.2 is forbidden.
+    let mut old_tls = allowed.clone();
+    old_tls.tls_version = Some(netlify_toml::TlsVersion::Tls12);
+    assert!(matches!(
+        config.resolve_redirect(&old_tls, |_| false),
+        Some(netlify_toml::Resolution::Forbidden { .. })
+    ));
+
+    // Missing the role is forbidden even on a modern connection.
+    let anonymous = netlify_toml::Request {
+        path: "/secure/dashboard".to_string(),
+        tls_version: Some(netlify_toml::TlsVersion::Tls13),
+        ..Default::default()
+    };
+    assert!(matches!(
+        config.resolve_redirect(&anonymous, |_| false),
+        Some(netlify_toml::Resolution::Forbidden { .. })
+    ));
+}
+
 #[test]
 fn parses_aliased_edge_handlers_name() {
     let io = r#"